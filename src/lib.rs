@@ -0,0 +1,10 @@
+//! 波动方程模拟的共享引擎：`src/bin/main.rs` 和 `src/bin/membrane.rs` 都通过这个库
+//! 访问求解器、边界条件、噪声初始场、播放缓动和能量诊断，而不是各自拷贝一份模块源码。
+//! 仓库根目录的 `main（wave packet）.rs` 不使用本库，仍自带它自己的差分循环。
+
+pub mod boundary;
+pub mod energy;
+pub mod noise;
+pub mod playback;
+pub mod solver;
+pub mod solver2d;
@@ -0,0 +1,95 @@
+//! Perlin风格的梯度噪声：用于生成平滑、带限的随机初始位移场。
+//!
+//! 经典实现依赖一张256项的随机排列表来给整数格点分配梯度；这里改用对整数坐标
+//! 做确定性哈希来取梯度方向，效果等价但不需要额外的随机数crate或大常量表。
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + t * (b - a)
+}
+
+/// 对整数坐标做确定性哈希（类似 "Squirrel3" 风格的整数混合）。
+fn hash2(ix: i64, iy: i64) -> u32 {
+    let mut h = ix.wrapping_mul(374_761_393).wrapping_add(iy.wrapping_mul(668_265_263));
+    h = (h ^ (h >> 13)).wrapping_mul(1_274_126_177);
+    (h ^ (h >> 16)) as u32
+}
+
+/// 1D的梯度只有两种取向，由哈希的最低位决定符号。
+fn gradient1(ix: i64) -> f64 {
+    if hash2(ix, 0) & 1 == 0 { 1.0 } else { -1.0 }
+}
+
+/// 2D梯度取单位圆上由哈希值决定的一个方向。
+fn gradient2(ix: i64, iy: i64) -> (f64, f64) {
+    let angle = (hash2(ix, iy) as f64 / u32::MAX as f64) * std::f64::consts::TAU;
+    (angle.cos(), angle.sin())
+}
+
+/// 单倍频一维Perlin噪声，值域约在[-1, 1]。
+pub fn perlin_1d(x: f64) -> f64 {
+    let i0 = x.floor() as i64;
+    let i1 = i0 + 1;
+    let t = x - i0 as f64;
+
+    let g0 = gradient1(i0) * t;
+    let g1 = gradient1(i1) * (t - 1.0);
+    lerp(g0, g1, fade(t))
+}
+
+/// 单倍频二维Perlin噪声，值域约在[-1, 1]。
+pub fn perlin_2d(x: f64, y: f64) -> f64 {
+    let ix0 = x.floor() as i64;
+    let iy0 = y.floor() as i64;
+    let ix1 = ix0 + 1;
+    let iy1 = iy0 + 1;
+    let tx = x - ix0 as f64;
+    let ty = y - iy0 as f64;
+
+    let dot = |ix: i64, iy: i64, dx: f64, dy: f64| {
+        let (gx, gy) = gradient2(ix, iy);
+        gx * dx + gy * dy
+    };
+
+    let n00 = dot(ix0, iy0, tx, ty);
+    let n10 = dot(ix1, iy0, tx - 1.0, ty);
+    let n01 = dot(ix0, iy1, tx, ty - 1.0);
+    let n11 = dot(ix1, iy1, tx - 1.0, ty - 1.0);
+
+    let u = fade(tx);
+    let v = fade(ty);
+    lerp(lerp(n00, n10, u), lerp(n01, n11, u), v)
+}
+
+/// 多倍频叠加（分形布朗运动）：每升一个倍频频率翻倍、振幅按 `persistence` 衰减。
+pub fn fractal_noise_1d(x: f64, octaves: u32, persistence: f64) -> f64 {
+    let mut total = 0.0;
+    let mut amplitude = 1.0;
+    let mut frequency = 1.0;
+    let mut max_amplitude = 0.0;
+    for _ in 0..octaves {
+        total += perlin_1d(x * frequency) * amplitude;
+        max_amplitude += amplitude;
+        amplitude *= persistence;
+        frequency *= 2.0;
+    }
+    total / max_amplitude
+}
+
+/// [`fractal_noise_1d`] 的二维版本，用作 `u(x,y,0)` 的粗糙地形/水面初始场。
+pub fn fractal_noise_2d(x: f64, y: f64, octaves: u32, persistence: f64) -> f64 {
+    let mut total = 0.0;
+    let mut amplitude = 1.0;
+    let mut frequency = 1.0;
+    let mut max_amplitude = 0.0;
+    for _ in 0..octaves {
+        total += perlin_2d(x * frequency, y * frequency) * amplitude;
+        max_amplitude += amplitude;
+        amplitude *= persistence;
+        frequency *= 2.0;
+    }
+    total / max_amplitude
+}
@@ -0,0 +1,91 @@
+//! 时间域超采样：在相邻两个计算帧之间插入渲染帧，配合缓动曲线做慢动作回放。
+
+/// 可选的插值缓动曲线：标准 Robert Penner tween `f(t,b,c,d)`，代入 `b=0, c=1, d=1`
+/// 后对播放比例 `s∈[0,1]` 做的简化形式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Easing {
+    /// 线性插值：out = A + (B-A)·s
+    Linear,
+    /// Sine: -cos(s·π/2) + 1
+    Sine,
+    /// Cubic easeInOut
+    Cubic,
+    /// Quad easeInOut
+    Quad,
+    /// Expo easeInOut
+    Expo,
+}
+
+impl Easing {
+    /// 把原始播放比例 `s` 映射成缓动后的比例。
+    pub fn apply(self, s: f64) -> f64 {
+        match self {
+            Easing::Linear => s,
+            Easing::Sine => 1.0 - (s * std::f64::consts::FRAC_PI_2).cos(),
+            Easing::Cubic => {
+                let mut t = s * 2.0;
+                if t < 1.0 {
+                    0.5 * t.powi(3)
+                } else {
+                    t -= 2.0;
+                    0.5 * (t.powi(3) + 2.0)
+                }
+            }
+            Easing::Quad => {
+                let mut t = s * 2.0;
+                if t < 1.0 {
+                    0.5 * t.powi(2)
+                } else {
+                    t -= 1.0;
+                    -0.5 * (t * (t - 2.0) - 1.0)
+                }
+            }
+            Easing::Expo => {
+                if s <= 0.0 {
+                    0.0
+                } else if s >= 1.0 {
+                    1.0
+                } else {
+                    let mut t = s * 2.0;
+                    if t < 1.0 {
+                        0.5 * 2f64.powf(10.0 * (t - 1.0))
+                    } else {
+                        t -= 1.0;
+                        0.5 * (2.0 - 2f64.powf(-10.0 * t))
+                    }
+                }
+            }
+        }
+    }
+
+    /// 用于HTML下拉框的标识符，和下拉框选项的value、页面里预先算好的帧表 `PRECOMPUTED` 的键一一对应。
+    pub fn js_key(self) -> &'static str {
+        match self {
+            Easing::Linear => "linear",
+            Easing::Sine => "sine",
+            Easing::Cubic => "cubic",
+            Easing::Quad => "quad",
+            Easing::Expo => "expo",
+        }
+    }
+}
+
+/// 在相邻帧之间插入 `k` 个渲染帧（含起点、不含终点），得到更平滑的慢动作序列。
+pub fn supersample(frames: &[Vec<f64>], k: usize, easing: Easing) -> Vec<Vec<f64>> {
+    if k == 0 || frames.len() < 2 {
+        return frames.to_vec();
+    }
+
+    let mut out = Vec::with_capacity((frames.len() - 1) * (k + 1) + 1);
+    for pair in frames.windows(2) {
+        let (a, b) = (&pair[0], &pair[1]);
+        out.push(a.clone());
+        for step in 1..=k {
+            let s = step as f64 / (k + 1) as f64;
+            let eased = easing.apply(s);
+            out.push(a.iter().zip(b.iter()).map(|(&av, &bv)| av + (bv - av) * eased).collect());
+        }
+    }
+    out.push(frames[frames.len() - 1].clone());
+    out
+}
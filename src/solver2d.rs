@@ -0,0 +1,70 @@
+//! 二维膜振动求解器：在 `Nx×Ny` 网格上求解二维波动方程，边框固定为0。
+
+/// 二维弦（膜）振动的有限差分求解器。
+///
+/// 位移场按行主序存储：`(i, j)` 对应下标 `i * ny + j`。
+pub struct WaveSolver2D {
+    nx: usize,
+    ny: usize,
+    dx: f64,
+    dt: f64,
+    c: f64,
+    u_prev: Vec<f64>,
+    u_curr: Vec<f64>,
+}
+
+impl WaveSolver2D {
+    /// 构造求解器，初始速度恒为0（`u_prev` = `u_curr`）。
+    pub fn new<F>(nx: usize, ny: usize, dx: f64, dt: f64, c: f64, u0: F) -> Self
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        let mut u_curr = vec![0.0; nx * ny];
+        for i in 0..nx {
+            for j in 0..ny {
+                u_curr[i * ny + j] = u0(i as f64 * dx, j as f64 * dx);
+            }
+        }
+        let u_prev = u_curr.clone();
+
+        Self { nx, ny, dx, dt, c, u_prev, u_curr }
+    }
+
+    fn idx(&self, i: usize, j: usize) -> usize {
+        i * self.ny + j
+    }
+
+    /// 推进一个时间步，返回新的位移场（同时更新内部状态）。
+    pub fn step(&mut self) -> Vec<f64> {
+        let r_sq = (self.c * self.dt / self.dx).powi(2);
+        let mut u_next = vec![0.0; self.nx * self.ny];
+
+        for i in 1..self.nx - 1 {
+            for j in 1..self.ny - 1 {
+                let idx = self.idx(i, j);
+                u_next[idx] = 2.0 * self.u_curr[idx] - self.u_prev[idx]
+                    + r_sq
+                        * (self.u_curr[self.idx(i + 1, j)]
+                            + self.u_curr[self.idx(i - 1, j)]
+                            + self.u_curr[self.idx(i, j + 1)]
+                            + self.u_curr[self.idx(i, j - 1)]
+                            - 4.0 * self.u_curr[idx]);
+            }
+        }
+        // 边框固定（膜的四周绷紧在框架上）
+
+        self.u_prev = std::mem::replace(&mut self.u_curr, u_next.clone());
+        u_next
+    }
+
+    /// 连续运行 `num_steps` 步，返回每一步（含开头两帧）的位移场。
+    pub fn run(&mut self, num_steps: usize) -> Vec<Vec<f64>> {
+        let mut frames = Vec::with_capacity(num_steps);
+        frames.push(self.u_prev.clone());
+        frames.push(self.u_curr.clone());
+        for _ in 2..num_steps {
+            frames.push(self.step());
+        }
+        frames
+    }
+}